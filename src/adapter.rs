@@ -9,8 +9,48 @@
 
 use Bits;
 use BitSliceable;
+use BlockType;
 
 use std::cmp;
+use std::ops;
+
+/// A mask with only the low `width` bits set (saturating at all bits set
+/// once `width >= Block::nbits()`).
+///
+/// Used throughout this module to clear the bits of a block beyond some
+/// adapter's logical length, since `get_block` on a composed adapter
+/// (e.g. [`BitsNot`]) has no guarantee that the bits past its own
+/// `bit_len()` are zero: it just computes bit-by-bit (or block-by-block)
+/// from its operands, garbage tail and all.
+fn low_mask<Block: BlockType>(width: usize) -> Block {
+    let nbits = Block::nbits();
+    if width == 0 {
+        Block::zero()
+    } else if width >= nbits {
+        !Block::zero()
+    } else {
+        !Block::zero() >> (nbits - width)
+    }
+}
+
+/// `bits.get_block(i)`, with any bits at or beyond `bits.bit_len()`
+/// cleared to zero.
+///
+/// Only the final block of `bits` can have such bits (every earlier
+/// block is entirely within `0 .. bits.bit_len()`), so this is a no-op
+/// except on the last call of a `0 .. bits.block_len()` scan.
+fn masked_block<T: Bits + ?Sized>(bits: &T, i: usize) -> T::Block {
+    let nbits = T::Block::nbits() as u64;
+    let start = i as u64 * nbits;
+    let bit_len = bits.bit_len();
+    let block = bits.get_block(i);
+
+    if start + nbits <= bit_len {
+        block
+    } else {
+        block & low_mask::<T::Block>((bit_len - start) as usize)
+    }
+}
 
 /// Extension trait for bit-wise logical operators on bit slices.
 ///
@@ -111,10 +151,280 @@ pub trait BitsLogic: Bits {
 
         BitsXor(BitsBinOp::new(self, other))
     }
+
+    /// Returns an object that lazily combines the blocks of two
+    /// bit-vector-likes with an arbitrary closure `f`.
+    ///
+    /// This generalizes [`bits_and`](#method.bits_and),
+    /// [`bits_or`](#method.bits_or), and [`bits_xor`](#method.bits_xor),
+    /// which give the same bits as `bits_zip` with `f` set to
+    /// `BitAnd::bitand`, `BitOr::bitor`, and `BitXor::bitxor` respectively
+    /// (they are separate adapter types, not built on `BitsZip`). It lets you
+    /// express other combinations (majority, and-not, nor, equivalence,
+    /// ...) without the crate growing a method for each one.
+    fn bits_zip<Other, F>(&self, other: Other, f: F) -> BitsZip<&Self, Other, F>
+        where Other: Bits<Block = Self::Block>,
+              F: Fn(Self::Block, Self::Block) -> Self::Block {
+
+        BitsZip { ops: BitsBinOp::new(self, other), f }
+    }
+
+    /// Returns an object that lazily combines the blocks of two
+    /// bit-vector-likes with an arbitrary closure `f`.
+    ///
+    /// Consumes `self`.
+    fn into_bits_zip<Other, F>(self, other: Other, f: F) -> BitsZip<Self, Other, F>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block>,
+              F: Fn(Self::Block, Self::Block) -> Self::Block {
+
+        BitsZip { ops: BitsBinOp::new(self, other), f }
+    }
+
+    /// Returns an object that conceptually extends `self` to `len` bits
+    /// by padding it with `false` at the end.
+    ///
+    /// This is useful for matching up the lengths of two differently-sized
+    /// bit-vector-likes before combining them with, e.g., [`bits_or`] or
+    /// [`bits_xor`], so that the tail of the longer operand is not lost.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is less than `self.bit_len()`.
+    ///
+    /// [`bits_or`]: #method.bits_or
+    /// [`bits_xor`]: #method.bits_xor
+    fn bit_pad(&self, len: u64) -> BitPad<&Self> {
+        assert!(len >= self.bit_len(),
+                "BitsLogic::bit_pad: new length shorter than old length");
+        BitPad { bits: self, len }
+    }
+
+    /// Returns an object that conceptually extends `self` to `len` bits
+    /// by padding it with `false` at the end.
+    ///
+    /// Consumes `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is less than `self.bit_len()`.
+    fn into_bit_pad(self, len: u64) -> BitPad<Self>
+        where Self: Sized {
+
+        assert!(len >= self.bit_len(),
+                "BitsLogic::into_bit_pad: new length shorter than old length");
+        BitPad { bits: self, len }
+    }
+
+    /// Returns an object that lazily concatenates `self` followed by
+    /// `other`, end to end.
+    ///
+    /// The resulting bit-vector-like has length
+    /// `self.bit_len() + other.bit_len()`.
+    fn bits_concat<Other>(&self, other: Other) -> BitsConcat<&Self, Other>
+        where Other: Bits<Block = Self::Block> {
+
+        BitsConcat {
+            len: self.bit_len() + other.bit_len(),
+            op1: self,
+            op2: other,
+        }
+    }
+
+    /// Returns an object that lazily concatenates `self` followed by
+    /// `other`, end to end.
+    ///
+    /// Consumes `self`.
+    fn into_bits_concat<Other>(self, other: Other) -> BitsConcat<Self, Other>
+        where Self: Sized,
+              Other: Bits<Block = Self::Block> {
+
+        BitsConcat {
+            len: self.bit_len() + other.bit_len(),
+            op1: self,
+            op2: other,
+        }
+    }
 }
 
 impl<T: Bits> BitsLogic for T {}
 
+/// Extension trait adding population-count, rank, and select operations
+/// to any `Bits`.
+///
+/// Because these are defined in terms of [`Bits::get_block`], they work
+/// directly on a lazy adapter like [`BitsAnd`] or [`BitsXor`] without
+/// materializing it into a `BitVec` first.
+///
+/// [`Bits::get_block`]: ../trait.Bits.html#tymethod.get_block
+/// [`BitsAnd`]: struct.BitsAnd.html
+/// [`BitsXor`]: struct.BitsXor.html
+pub trait BitsCount: Bits {
+    /// Counts the number of one bits.
+    fn count_ones(&self) -> u64 {
+        let mut total = 0u64;
+        for i in 0 .. self.block_len() {
+            // `get_block`'s final block isn't guaranteed to be zeroed
+            // past `bit_len()` (a composed adapter like `bits_not()`
+            // leaves garbage there), so mask it before counting.
+            total += masked_block(self, i).count_ones() as u64;
+        }
+        total
+    }
+
+    /// Counts the number of zero bits.
+    fn count_zeros(&self) -> u64 {
+        self.bit_len() - self.count_ones()
+    }
+
+    /// Counts the number of one bits strictly before `pos`.
+    ///
+    /// # Panics
+    ///
+    /// May panic or return nonsense if `pos > self.bit_len()`.
+    fn rank1(&self, pos: u64) -> u64 {
+        let nbits = Self::Block::nbits() as u64;
+        let block_index = (pos / nbits) as usize;
+
+        let mut total = 0u64;
+        for i in 0 .. block_index {
+            total += self.get_block(i).count_ones() as u64;
+        }
+
+        let bit_offset = (pos % nbits) as usize;
+        if bit_offset > 0 {
+            let block = self.get_block(block_index);
+            for bit in 0 .. bit_offset {
+                if block.get_bit(bit) {
+                    total += 1;
+                }
+            }
+        }
+
+        total
+    }
+
+    /// Counts the number of zero bits strictly before `pos`.
+    fn rank0(&self, pos: u64) -> u64 {
+        pos - self.rank1(pos)
+    }
+
+    /// Finds the position of the `k`th one bit (0-indexed), or `None` if
+    /// there are fewer than `k + 1` one bits.
+    fn select1(&self, k: u64) -> Option<u64> {
+        let nbits = Self::Block::nbits();
+
+        let mut seen = 0u64;
+        for i in 0 .. self.block_len() {
+            // As in `count_ones`, the final block may have dirty bits
+            // past `bit_len()` that must not be counted or selected.
+            let block = masked_block(self, i);
+            let ones = block.count_ones() as u64;
+
+            if seen + ones > k {
+                let target = k - seen;
+                let mut count = 0u64;
+                for bit in 0 .. nbits {
+                    if block.get_bit(bit) {
+                        if count == target {
+                            return Some(i as u64 * nbits as u64 + bit as u64);
+                        }
+                        count += 1;
+                    }
+                }
+                unreachable!("count_ones and get_bit disagree");
+            }
+
+            seen += ones;
+        }
+
+        None
+    }
+}
+
+impl<T: Bits> BitsCount for T {}
+
+/// Extension trait adding block and bit iteration to any `Bits`.
+///
+/// This lets a deep chain of lazy adapters (e.g.
+/// `a.bits_and(&b).into_bits_xor(c)`) be evaluated one block at a time,
+/// without per-bit virtual dispatch, by driving [`blocks`](#method.blocks)
+/// instead of indexing [`get_bit`] one position at a time.
+///
+/// FOLLOW-UP (not yet done): the motivating use case is
+/// `BitVec::from_bits` consuming `blocks()` instead of `get_bit`, so that
+/// evaluating a chain like the one above streams block-at-a-time all the
+/// way through. `BitVec::from_bits` lives in `vec.rs`, which is outside
+/// this module, so that change is still outstanding.
+///
+/// [`get_bit`]: ../trait.Bits.html#tymethod.get_bit
+pub trait BitsIter: Bits {
+    /// An iterator over every block of `self`, in order.
+    ///
+    /// The final block is masked to `self.bit_len()`, even if
+    /// `self.get_block` itself wouldn't have zeroed those bits.
+    fn blocks(&self) -> Blocks<Self> {
+        Blocks { bits: self, range: 0 .. self.block_len() }
+    }
+
+    /// An iterator over every bit of `self`, in order.
+    fn iter_bits(&self) -> IterBits<Self> {
+        IterBits { bits: self, range: 0 .. self.bit_len() }
+    }
+}
+
+impl<T: Bits> BitsIter for T {}
+
+/// The result of [`BitsIter::blocks`](trait.BitsIter.html#method.blocks).
+pub struct Blocks<'a, T: 'a + ?Sized> {
+    bits: &'a T,
+    range: ::std::ops::Range<usize>,
+}
+
+impl<'a, T: Bits + ?Sized> Iterator for Blocks<'a, T> {
+    type Item = T::Block;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| masked_block(self.bits, i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a, T: Bits + ?Sized> ExactSizeIterator for Blocks<'a, T> {
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl<'a, T: Bits + ?Sized> DoubleEndedIterator for Blocks<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| masked_block(self.bits, i))
+    }
+}
+
+/// The result of [`BitsIter::iter_bits`](trait.BitsIter.html#method.iter_bits).
+pub struct IterBits<'a, T: 'a + ?Sized> {
+    bits: &'a T,
+    range: ::std::ops::Range<u64>,
+}
+
+impl<'a, T: Bits + ?Sized> Iterator for IterBits<'a, T> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.bits.get_bit(i))
+    }
+}
+
+impl<'a, T: Bits + ?Sized> DoubleEndedIterator for IterBits<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| self.bits.get_bit(i))
+    }
+}
+
 /// The result of [`BitsLogic::bits_not`](trait.BitsLogic.html#method.bits_not).
 ///
 /// The resulting bit vector adapter *not*s the bits of the underlying
@@ -143,6 +453,63 @@ pub struct BitsOr<T, U>(BitsBinOp<T, U>);
 #[derive(Clone, Debug)]
 pub struct BitsXor<T, U>(BitsBinOp<T, U>);
 
+/// The result of [`BitsLogic::bits_zip`](trait.BitsLogic.html#method.bits_zip).
+///
+/// The resulting bit vector adapter combines the blocks of the two
+/// underlying bit-vector-likes with the closure `F`.
+pub struct BitsZip<T, U, F> {
+    ops: BitsBinOp<T, U>,
+    f: F,
+}
+
+/// The result of [`BitFill::zeroes`](struct.BitFill.html#method.zeroes) or
+/// [`BitFill::ones`](struct.BitFill.html#method.ones).
+///
+/// A zero-storage bit-vector-like that returns the same bit for every
+/// position. Useful as a cheap all-zero/all-one operand, or for padding
+/// a shorter operand up to a common length (see
+/// [`BitsLogic::bit_pad`](trait.BitsLogic.html#method.bit_pad)).
+#[derive(Clone, Debug)]
+pub struct BitFill<Block> {
+    len: u64,
+    bit: bool,
+    marker: ::std::marker::PhantomData<Block>,
+}
+
+impl<Block: BlockType> BitFill<Block> {
+    /// Constructs a `BitFill` of `len` zero bits.
+    pub fn zeroes(len: u64) -> Self {
+        BitFill { len, bit: false, marker: ::std::marker::PhantomData }
+    }
+
+    /// Constructs a `BitFill` of `len` one bits.
+    pub fn ones(len: u64) -> Self {
+        BitFill { len, bit: true, marker: ::std::marker::PhantomData }
+    }
+}
+
+/// The result of [`BitsLogic::bit_pad`](trait.BitsLogic.html#method.bit_pad).
+///
+/// The resulting bit vector adapter conceptually extends the underlying
+/// bit-vector-like with `false` bits out to a target length.
+#[derive(Clone, Debug)]
+pub struct BitPad<T> {
+    bits: T,
+    len: u64,
+}
+
+/// The result of
+/// [`BitsLogic::bits_concat`](trait.BitsLogic.html#method.bits_concat).
+///
+/// The resulting bit vector adapter is `op1` followed by `op2`, end to
+/// end.
+#[derive(Clone, Debug)]
+pub struct BitsConcat<T, U> {
+    op1: T,
+    op2: U,
+    len: u64,
+}
+
 /// Used to store the two operands to a bitwise logical operation on
 /// `Bits`es, along with the length of the result (min the length of
 /// the operands) and the offset of the result (see invariant below).
@@ -251,6 +618,164 @@ impl<T, U> Bits for BitsXor<T, U>
     }
 }
 
+impl<T, U, F> Bits for BitsZip<T, U, F>
+    where T: Bits,
+          U: Bits<Block = T::Block>,
+          F: Fn(T::Block, T::Block) -> T::Block
+{
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.ops.len
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        let nbits = Self::Block::nbits() as u64;
+        let block = self.get_block((position / nbits) as usize);
+        block.get_bit((position % nbits) as usize)
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        (self.f)(self.ops.block1(position), self.ops.block2(position))
+    }
+}
+
+impl<Block: BlockType> Bits for BitFill<Block> {
+    type Block = Block;
+
+    fn bit_len(&self) -> u64 {
+        self.len
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        assert!(position < self.len, "BitFill::get_bit: out of bounds");
+        self.bit
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        let nbits = Self::Block::nbits() as u64;
+        let start = position as u64 * nbits;
+
+        if !self.bit || start >= self.len {
+            return Self::Block::zero();
+        }
+
+        low_mask::<Self::Block>((self.len - start) as usize)
+    }
+}
+
+impl<T: Bits> Bits for BitPad<T> {
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.len
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        if position < self.bits.bit_len() {
+            self.bits.get_bit(position)
+        } else {
+            false
+        }
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        if position < self.bits.block_len() {
+            // `masked_block` clears any bits at or beyond
+            // `self.bits.bit_len()`, which is exactly the padding this
+            // adapter is supposed to contribute once a block straddles
+            // that boundary.
+            masked_block(&self.bits, position)
+        } else {
+            Self::Block::zero()
+        }
+    }
+}
+
+impl<T, U> Bits for BitsConcat<T, U>
+    where T: Bits,
+          U: Bits<Block = T::Block>
+{
+    type Block = T::Block;
+
+    fn bit_len(&self) -> u64 {
+        self.len
+    }
+
+    fn get_bit(&self, position: u64) -> bool {
+        let split = self.op1.bit_len();
+        if position < split {
+            self.op1.get_bit(position)
+        } else {
+            self.op2.get_bit(position - split)
+        }
+    }
+
+    fn get_block(&self, position: usize) -> Self::Block {
+        let nbits = Self::Block::nbits();
+        let split = (self.op1.bit_len() % nbits as u64) as usize;
+        let op1_blocks = self.op1.block_len();
+
+        if split == 0 {
+            // Aligned seam: op1 and op2 each contribute whole blocks.
+            if position < op1_blocks {
+                self.op1.get_block(position)
+            } else {
+                self.op2.get_block(position - op1_blocks)
+            }
+        } else if position < op1_blocks - 1 {
+            self.op1.get_block(position)
+        } else if position == op1_blocks - 1 {
+            // The block straddling the seam: op1's tail, or-ed with op2's
+            // leading bits shifted up into place. `op1`'s own `get_block`
+            // isn't guaranteed to have zeroed the bits past its own
+            // `bit_len()` (e.g. a `bits_not()` source leaves garbage
+            // there), so mask it down to its low `split` bits explicitly
+            // before mixing in op2. op2 may be empty, in which case there
+            // is no leading block to pull in and op1's tail is the whole
+            // answer.
+            let op1_tail = masked_block(&self.op1, position);
+            if self.op2.block_len() > 0 {
+                op1_tail | (self.op2.get_block(0) << split)
+            } else {
+                op1_tail
+            }
+        } else {
+            // Purely within op2, but shifted by `split` bits relative to
+            // op2's own block boundaries, so each result block is made
+            // up of the tail of one op2 block and the head of the next.
+            let j = position - op1_blocks;
+            let op2_blocks = self.op2.block_len();
+            let low = self.op2.get_block(j) >> (nbits - split);
+            let high = if j + 1 < op2_blocks {
+                self.op2.get_block(j + 1) << split
+            } else {
+                Self::Block::zero()
+            };
+            low | high
+        }
+    }
+}
+
+impl<R, T, U, F> BitSliceable<R> for BitsZip<T, U, F>
+    where R: Clone,
+          T: BitSliceable<R> + Bits,
+          U: BitSliceable<R> + Bits<Block = T::Block>,
+          T::Slice: Bits<Block = T::Block>,
+          U::Slice: Bits<Block = T::Block>,
+          F: Fn(T::Block, T::Block) -> T::Block {
+
+    type Slice = BitsZip<T::Slice, U::Slice, F>;
+
+    fn bit_slice(self, range: R) -> Self::Slice {
+        BitsZip {
+            ops: BitsBinOp::new(self.ops.op1.bit_slice(range.clone()),
+                                 self.ops.op2.bit_slice(range)),
+            f: self.f,
+        }
+    }
+}
+
 impl<R, T> BitSliceable<R> for BitsNot<T>
     where T: BitSliceable<R> {
 
@@ -306,10 +831,130 @@ impl<R, T, U> BitSliceable<R> for BitsXor<T, U>
     }
 }
 
+impl<R, T, U> BitSliceable<R> for BitsConcat<T, U>
+    where R: Clone,
+          T: BitSliceable<R> + Bits,
+          U: BitSliceable<R> + Bits<Block = T::Block>,
+          T::Slice: Bits<Block = T::Block>,
+          U::Slice: Bits<Block = T::Block> {
+
+    type Slice = BitsConcat<T::Slice, U::Slice>;
+
+    fn bit_slice(self, range: R) -> Self::Slice {
+        let op1 = self.op1.bit_slice(range.clone());
+        let op2 = self.op2.bit_slice(range);
+        let len = op1.bit_len() + op2.bit_len();
+        BitsConcat { op1, op2, len }
+    }
+}
+
+// `std::ops` overloads, so that the lazy logic adapters can be combined
+// with `&`, `|`, `^`, and `!` instead of only the `bits_and`/etc. methods.
+// Each operator is implemented both for owned adapters (consuming,
+// mirroring `into_bits_and`/etc.) and for references (borrowing,
+// mirroring `bits_and`/etc.), so the operators stay consistent with the
+// trait's borrow/consume split.
+//
+// A blanket impl over every `Bits` type isn't possible here (the orphan
+// rules forbid `impl<T: Bits> ops::BitAnd<..> for &T`, since neither the
+// trait nor the bare type parameter `T` is local), so `Self` below is
+// always one of this module's own adapter types
+// (`BitsNot`/`BitsAnd`/`BitsOr`/`BitsXor`) rather than every `Bits` impl.
+// `BitVec`/`BitSlice` are themselves local to this crate and so *could*
+// get concrete `impl ops::BitAnd for &BitVec`-style impls without any
+// orphan-rule trouble, but they live in `vec.rs`/`slice.rs`, outside this
+// module, so that's left as follow-up work for whoever owns those files.
+// Until then, these impls at least make chains of adapters, like
+// `!(a.bits_and(&b) & &c)`, read naturally.
+macro_rules! impl_logic_ops {
+    ($Ty:ident, $($param:ident: $bound:path),+) => {
+        impl<$($param: $bound),+, Other: Bits<Block = T::Block>> ops::BitAnd<Other>
+            for $Ty<$($param),+> {
+
+            type Output = BitsAnd<Self, Other>;
+
+            fn bitand(self, other: Other) -> Self::Output {
+                self.into_bits_and(other)
+            }
+        }
+
+        impl<'a, $($param: $bound),+, Other: Bits<Block = T::Block>> ops::BitAnd<Other>
+            for &'a $Ty<$($param),+> {
+
+            type Output = BitsAnd<Self, Other>;
+
+            fn bitand(self, other: Other) -> Self::Output {
+                self.bits_and(other)
+            }
+        }
+
+        impl<$($param: $bound),+, Other: Bits<Block = T::Block>> ops::BitOr<Other>
+            for $Ty<$($param),+> {
+
+            type Output = BitsOr<Self, Other>;
+
+            fn bitor(self, other: Other) -> Self::Output {
+                self.into_bits_or(other)
+            }
+        }
+
+        impl<'a, $($param: $bound),+, Other: Bits<Block = T::Block>> ops::BitOr<Other>
+            for &'a $Ty<$($param),+> {
+
+            type Output = BitsOr<Self, Other>;
+
+            fn bitor(self, other: Other) -> Self::Output {
+                self.bits_or(other)
+            }
+        }
+
+        impl<$($param: $bound),+, Other: Bits<Block = T::Block>> ops::BitXor<Other>
+            for $Ty<$($param),+> {
+
+            type Output = BitsXor<Self, Other>;
+
+            fn bitxor(self, other: Other) -> Self::Output {
+                self.into_bits_xor(other)
+            }
+        }
+
+        impl<'a, $($param: $bound),+, Other: Bits<Block = T::Block>> ops::BitXor<Other>
+            for &'a $Ty<$($param),+> {
+
+            type Output = BitsXor<Self, Other>;
+
+            fn bitxor(self, other: Other) -> Self::Output {
+                self.bits_xor(other)
+            }
+        }
+
+        impl<$($param: $bound),+> ops::Not for $Ty<$($param),+> {
+            type Output = BitsNot<Self>;
+
+            fn not(self) -> Self::Output {
+                self.into_bits_not()
+            }
+        }
+
+        impl<'a, $($param: $bound),+> ops::Not for &'a $Ty<$($param),+> {
+            type Output = BitsNot<Self>;
+
+            fn not(self) -> Self::Output {
+                self.bits_not()
+            }
+        }
+    }
+}
+
+impl_logic_ops!(BitsNot, T: Bits);
+impl_logic_ops!(BitsAnd, T: Bits, U: Bits<Block = T::Block>);
+impl_logic_ops!(BitsOr, T: Bits, U: Bits<Block = T::Block>);
+impl_logic_ops!(BitsXor, T: Bits, U: Bits<Block = T::Block>);
+
 #[cfg(test)]
 mod test {
     use {Bits, BitVec, BitSliceable};
-    use super::BitsLogic;
+    use super::{BitsLogic, BitsCount, BitsIter, BitFill};
 
     fn assert_0001<T: Bits>(bits: &T) {
         assert_eq!( bits.bit_len(), 4 );
@@ -348,6 +993,64 @@ mod test {
         assert_0001(&and_bits);
     }
 
+    #[test]
+    fn simple_zip() {
+        let bv1: BitVec<u8> = bit_vec![ false, false, true, true, ];
+        let bv2: BitVec<u8> = bit_vec![ false, true, false, true, ];
+        let zip_bits = bv1.bits_zip(&bv2, |a, b| a & b);
+        assert_0001(&zip_bits);
+    }
+
+    #[test]
+    fn bit_fill() {
+        let zeroes: BitFill<u8> = BitFill::zeroes(10);
+        assert_eq!( zeroes.bit_len(), 10 );
+        assert!( !zeroes.get_bit(0) );
+        assert!( !zeroes.get_bit(9) );
+
+        let ones: BitFill<u8> = BitFill::ones(10);
+        assert_eq!( ones.bit_len(), 10 );
+        assert!( ones.get_bit(0) );
+        assert!( ones.get_bit(9) );
+
+        let bv = BitVec::from_bits(&ones);
+        assert_eq!( bv.bit_len(), 10 );
+        assert_eq!( bv.get_bit(9), true );
+    }
+
+    #[test]
+    fn bit_pad() {
+        let bv: BitVec<u8> = bit_vec![ true, false, true ];
+        let padded = bv.bit_pad(6);
+        assert_eq!( padded.bit_len(), 6 );
+        assert_eq!( padded.get_bit(0), true );
+        assert_eq!( padded.get_bit(1), false );
+        assert_eq!( padded.get_bit(2), true );
+        assert_eq!( padded.get_bit(3), false );
+        assert_eq!( padded.get_bit(5), false );
+    }
+
+    #[test]
+    fn bit_pad_masks_dirty_tail() {
+        // `bv.bits_not()` has a 3-bit logical length, but the underlying
+        // block's unused high bits get flipped from 0 to 1 by `!`, so its
+        // `get_block` does *not* come back pre-zeroed past bit 3.
+        let bv: BitVec<u8> = bit_vec![ true, false, true ];
+        let dirty = bv.bits_not();
+        assert_ne!( dirty.get_block(0) >> 3, 0,
+                    "test is only meaningful if the source block is actually dirty" );
+
+        let padded = dirty.bit_pad(6);
+        assert_eq!( padded.bit_len(), 6 );
+        assert_eq!( padded.get_bit(3), false );
+        assert_eq!( padded.get_bit(4), false );
+        assert_eq!( padded.get_bit(5), false );
+
+        // The padding bits must read as zero at the block level too, not
+        // just through `get_bit`.
+        assert_eq!( padded.get_block(0) >> 3, 0 );
+    }
+
     #[test]
     fn and_with_different_offset() {
         let bv1: BitVec<u8> = bit_vec![ true, true, false, false, true, true ];
@@ -357,4 +1060,164 @@ mod test {
         let and_bits = bv_slice1.bits_and(&bv_slice2);
         assert_0001(&and_bits);
     }
+
+    #[test]
+    fn concat_aligned() {
+        let bv1: BitVec<u8> = bit_vec![ false, false, false, false, false, false, false, false ];
+        let bv2: BitVec<u8> = bit_vec![ false, false, false, true ];
+        let concat_bits = bv1.bits_concat(&bv2);
+        assert_eq!( concat_bits.bit_len(), 12 );
+        for i in 0 .. 11 {
+            assert!( !concat_bits.get_bit(i) );
+        }
+        assert!( concat_bits.get_bit(11) );
+
+        let bv = BitVec::from_bits(&concat_bits);
+        assert_eq!( bv.get_bit(11), true );
+    }
+
+    #[test]
+    fn concat_unaligned() {
+        let bv1: BitVec<u8> = bit_vec![ false, false, false ];
+        let bv2: BitVec<u8> = bit_vec![ false, true ];
+        let concat_bits = bv1.bits_concat(&bv2);
+        assert_0001(&concat_bits);
+    }
+
+    #[test]
+    fn concat_with_empty_operand() {
+        let bv1: BitVec<u8> = bit_vec![ false, false, false, true ];
+        let bv2: BitVec<u8> = bit_vec![];
+        let concat_bits = bv1.bits_concat(&bv2);
+        assert_0001(&concat_bits);
+    }
+
+    #[test]
+    fn concat_seam_masks_dirty_op1_tail() {
+        // `bv1.bits_not()` has a dirty tail past its own 3-bit length (the
+        // unused high bits of the storage block get flipped to 1 by `!`),
+        // which must not leak into the straddling seam block.
+        let bv1: BitVec<u8> = bit_vec![ false, false, false ];
+        let bv2: BitVec<u8> = bit_vec![ false, true ];
+        let concat_bits = bv1.bits_not().bits_concat(&bv2);
+
+        assert_eq!( concat_bits.bit_len(), 5 );
+        assert_eq!( concat_bits.get_bit(0), true );
+        assert_eq!( concat_bits.get_bit(1), true );
+        assert_eq!( concat_bits.get_bit(2), true );
+        assert_eq!( concat_bits.get_bit(3), false );
+        assert_eq!( concat_bits.get_bit(4), true );
+
+        let bv = BitVec::from_bits(&concat_bits);
+        assert_eq!( bv, bit_vec![ true, true, true, false, true ] );
+    }
+
+    #[test]
+    fn concat_unaligned_multi_block_op2() {
+        let bv1: BitVec<u8> = bit_vec![ false, false, false ];
+        let bv2: BitVec<u8> = bit_vec![ false, false, false, false, false, false, false, false,
+                                        false, false, false, false, false, false, false, false,
+                                        false, false, false, true ];
+        let concat_bits = bv1.bits_concat(&bv2);
+        assert_eq!( concat_bits.bit_len(), 23 );
+        for i in 0 .. 22 {
+            assert!( !concat_bits.get_bit(i) );
+        }
+        assert!( concat_bits.get_bit(22) );
+
+        let bv = BitVec::from_bits(&concat_bits);
+        assert_eq!( bv.get_bit(22), true );
+    }
+
+    #[test]
+    fn operator_overloads() {
+        let bv1: BitVec<u8> = bit_vec![ false, false, true, true, ];
+        let bv2: BitVec<u8> = bit_vec![ false, true, false, true, ];
+        let and_bits = bv1.bits_and(&bv2);
+        assert_0001(&!!(&and_bits));
+        assert_0001(&(and_bits & BitFill::ones(4)));
+    }
+
+    #[test]
+    fn count_rank_select() {
+        let bv: BitVec<u8> = bit_vec![ true, false, true, true, false, false, true, false,
+                                       true, false ];
+
+        assert_eq!( bv.count_ones(), 5 );
+        assert_eq!( bv.count_zeros(), 5 );
+
+        assert_eq!( bv.rank1(0), 0 );
+        assert_eq!( bv.rank1(3), 2 );
+        assert_eq!( bv.rank1(10), 5 );
+        assert_eq!( bv.rank0(3), 1 );
+
+        assert_eq!( bv.select1(0), Some(0) );
+        assert_eq!( bv.select1(1), Some(2) );
+        assert_eq!( bv.select1(4), Some(8) );
+        assert_eq!( bv.select1(5), None );
+    }
+
+    #[test]
+    fn count_over_adapter() {
+        let bv1: BitVec<u8> = bit_vec![ false, false, true, true, ];
+        let bv2: BitVec<u8> = bit_vec![ false, true, false, true, ];
+        let and_bits = bv1.bits_and(&bv2);
+        assert_eq!( and_bits.count_ones(), 1 );
+        assert_eq!( and_bits.select1(0), Some(3) );
+    }
+
+    #[test]
+    fn count_over_dirty_tail_adapter() {
+        // `bits_not()` of a length not a multiple of the block width
+        // flips the unused high bits of its last block too; count_ones,
+        // count_zeros, and select1 must not be fooled by that garbage.
+        let bv: BitVec<u8> = bit_vec![ true, false, true ];
+        let dirty = bv.bits_not();
+        assert_ne!( dirty.get_block(0) >> 3, 0,
+                    "test is only meaningful if the source block is actually dirty" );
+
+        assert_eq!( dirty.bit_len(), 3 );
+        assert_eq!( dirty.count_ones(), 1 );
+        assert_eq!( dirty.count_zeros(), 2 );
+        assert_eq!( dirty.select1(0), Some(1) );
+        assert_eq!( dirty.select1(1), None );
+    }
+
+    #[test]
+    fn iter_bits_and_blocks() {
+        let bv: BitVec<u8> = bit_vec![ false, false, false, true ];
+
+        let bits: Vec<bool> = bv.iter_bits().collect();
+        assert_eq!( bits, vec![ false, false, false, true ] );
+
+        let blocks: Vec<u8> = bv.blocks().collect();
+        assert_eq!( blocks.len(), 1 );
+        assert_eq!( blocks[0], bv.get_block(0) );
+
+        assert_eq!( bv.iter_bits().rev().collect::<Vec<_>>(),
+                    vec![ true, false, false, false ] );
+    }
+
+    #[test]
+    fn blocks_masks_dirty_tail() {
+        // Same dirty-tail source as the `bits_concat`/`bit_pad` tests:
+        // `bits_not()` flips the unused high bits of its last block too,
+        // so `blocks()` must not hand those back unmasked.
+        let bv: BitVec<u8> = bit_vec![ true, false, true ];
+        let dirty = bv.bits_not();
+        assert_ne!( dirty.get_block(0) >> 3, 0,
+                    "test is only meaningful if the source block is actually dirty" );
+
+        let blocks: Vec<u8> = dirty.blocks().collect();
+        assert_eq!( blocks.len(), 1 );
+        assert_eq!( blocks[0] >> 3, 0 );
+
+        // Independently computed from the known bit values (not via
+        // `get_block`), so this can't pass merely "by construction".
+        let expected: u8 = (false as u8) | (true as u8) << 1 | (false as u8) << 2;
+        assert_eq!( blocks[0], expected );
+
+        let rev_blocks: Vec<u8> = dirty.blocks().rev().collect();
+        assert_eq!( rev_blocks, blocks );
+    }
 }
\ No newline at end of file